@@ -0,0 +1,37 @@
+//! Constant-time comparison of computed digests against expected values.
+//!
+//! Shared by the hash crates in this workspace so that comparing a
+//! Streebog/RIPEMD digest (or HMAC tag) against an expected value doesn't
+//! require each crate to hand-roll its own constant-time compare.
+
+#![no_std]
+#![forbid(unsafe_code)]
+
+use digest::generic_array::{ArrayLength, GenericArray};
+use subtle::ConstantTimeEq;
+
+/// Compares two byte slices in constant time, returning `true` if they are
+/// equal.
+///
+/// Length is checked up front (mismatched lengths can never be equal, so
+/// leaking that comparison leaks nothing about the digest itself); the
+/// byte-by-byte comparison is delegated to [`subtle::ConstantTimeEq`], which
+/// guarantees no early exit on the first differing byte.
+pub fn ct_eq(a: &[u8], b: &[u8]) -> bool {
+    if a.len() != b.len() {
+        return false;
+    }
+    a.ct_eq(b).into()
+}
+
+/// Extension trait adding a constant-time `verify` method to digest outputs.
+pub trait VerifyOutput {
+    /// Compares this digest against `expected` in constant time.
+    fn verify(&self, expected: &[u8]) -> bool;
+}
+
+impl<N: ArrayLength<u8>> VerifyOutput for GenericArray<u8, N> {
+    fn verify(&self, expected: &[u8]) -> bool {
+        ct_eq(self, expected)
+    }
+}
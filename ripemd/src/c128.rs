@@ -0,0 +1,148 @@
+use digest::generic_array::{typenum::U64, GenericArray};
+
+pub(crate) const DIGEST_BUF_LEN: usize = 4;
+
+#[rustfmt::skip]
+pub(crate) const H0: [u32; DIGEST_BUF_LEN] = [
+    0x6745_2301, 0xefcd_ab89, 0x98ba_dcfe, 0x1032_5476,
+];
+
+const K: [u32; 4] = [0x0000_0000, 0x5a82_7999, 0x6ed9_eba1, 0x8f1b_bcdc];
+const KK: [u32; 4] = [0x50a2_8be6, 0x5c4d_d124, 0x6d70_3ef3, 0x0000_0000];
+
+#[rustfmt::skip]
+const R: [usize; 64] = [
+    0, 1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12, 13, 14, 15,
+    7, 4, 13, 1, 10, 6, 15, 3, 12, 0, 9, 5, 2, 14, 11, 8,
+    3, 10, 14, 4, 9, 15, 8, 1, 2, 7, 0, 6, 13, 11, 5, 12,
+    1, 9, 11, 10, 0, 8, 12, 4, 13, 3, 7, 15, 14, 5, 6, 2,
+];
+
+#[rustfmt::skip]
+const RR: [usize; 64] = [
+    5, 14, 7, 0, 9, 2, 11, 4, 13, 6, 15, 8, 1, 10, 3, 12,
+    6, 11, 3, 7, 0, 13, 5, 10, 14, 15, 8, 12, 4, 9, 1, 2,
+    15, 5, 1, 3, 7, 14, 6, 9, 11, 8, 12, 2, 10, 0, 4, 13,
+    8, 6, 4, 1, 3, 11, 15, 0, 5, 12, 2, 13, 9, 7, 10, 14,
+];
+
+#[rustfmt::skip]
+const S: [u32; 64] = [
+    11, 14, 15, 12, 5, 8, 7, 9, 11, 13, 14, 15, 6, 7, 9, 8,
+    7, 6, 8, 13, 11, 9, 7, 15, 7, 12, 15, 9, 11, 7, 13, 12,
+    11, 13, 6, 7, 14, 9, 13, 15, 14, 8, 13, 6, 5, 12, 7, 5,
+    11, 12, 14, 15, 14, 15, 9, 8, 9, 14, 5, 6, 8, 6, 5, 12,
+];
+
+#[rustfmt::skip]
+const SS: [u32; 64] = [
+    8, 9, 9, 11, 13, 15, 15, 5, 7, 7, 8, 11, 14, 14, 12, 6,
+    9, 13, 15, 7, 12, 8, 9, 11, 7, 7, 12, 7, 6, 15, 13, 11,
+    9, 7, 15, 11, 8, 6, 6, 14, 12, 13, 5, 14, 13, 13, 7, 5,
+    15, 5, 8, 11, 14, 14, 6, 14, 6, 9, 12, 9, 12, 5, 15, 8,
+];
+
+#[inline(always)]
+fn f1(x: u32, y: u32, z: u32) -> u32 {
+    x ^ y ^ z
+}
+
+#[inline(always)]
+fn f2(x: u32, y: u32, z: u32) -> u32 {
+    (x & y) | (!x & z)
+}
+
+#[inline(always)]
+fn f3(x: u32, y: u32, z: u32) -> u32 {
+    (x | !y) ^ z
+}
+
+#[inline(always)]
+fn f4(x: u32, y: u32, z: u32) -> u32 {
+    (x & z) | (y & !z)
+}
+
+#[allow(clippy::many_single_char_names)]
+pub(crate) fn compress(h: &mut [u32; DIGEST_BUF_LEN], block: &GenericArray<u8, U64>) {
+    let mut w = [0u32; 16];
+    for (o, chunk) in w.iter_mut().zip(block.chunks_exact(4)) {
+        *o = u32::from_le_bytes(chunk.try_into().unwrap());
+    }
+
+    let [mut a1, mut b1, mut c1, mut d1] = *h;
+    let [mut a2, mut b2, mut c2, mut d2] = *h;
+
+    for i in 0..64 {
+        let round = i / 16;
+        let f = [f1, f2, f3, f4][round];
+        let ff = [f4, f3, f2, f1][round];
+
+        let t = a1
+            .wrapping_add(f(b1, c1, d1))
+            .wrapping_add(w[R[i]])
+            .wrapping_add(K[round])
+            .rotate_left(S[i]);
+        a1 = d1;
+        d1 = c1;
+        c1 = b1;
+        b1 = t;
+
+        let tt = a2
+            .wrapping_add(ff(b2, c2, d2))
+            .wrapping_add(w[RR[i]])
+            .wrapping_add(KK[round])
+            .rotate_left(SS[i]);
+        a2 = d2;
+        d2 = c2;
+        c2 = b2;
+        b2 = tt;
+    }
+
+    let t = h[1].wrapping_add(c1).wrapping_add(d2);
+    h[1] = h[2].wrapping_add(d1).wrapping_add(a2);
+    h[2] = h[3].wrapping_add(a1).wrapping_add(b2);
+    h[3] = h[0].wrapping_add(b1).wrapping_add(c2);
+    h[0] = t;
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{Digest, Ripemd128};
+    use hex_literal::hex;
+
+    // Official RIPEMD-128 test vectors, from the algorithm's 1996 spec.
+    fn digest(msg: &[u8]) -> [u8; 16] {
+        Ripemd128::digest(msg).into()
+    }
+
+    #[test]
+    fn empty() {
+        assert_eq!(digest(b""), hex!("cdf26213a150dc3ecb610f18f6b38b46"));
+    }
+
+    #[test]
+    fn a() {
+        assert_eq!(digest(b"a"), hex!("86be7afa339d0fc7cfc785e72f578d33"));
+    }
+
+    #[test]
+    fn abc() {
+        assert_eq!(digest(b"abc"), hex!("c14a12199c66e4ba84636b0f69144c77"));
+    }
+
+    #[test]
+    fn message_digest() {
+        assert_eq!(
+            digest(b"message digest"),
+            hex!("9e327b3d6e523062afc1132d7df9d1b8")
+        );
+    }
+
+    #[test]
+    fn alphabet() {
+        assert_eq!(
+            digest(b"abcdefghijklmnopqrstuvwxyz"),
+            hex!("fd2aa607f71dc8f510714922b371834e")
+        );
+    }
+}
@@ -31,6 +31,14 @@
 //!     f1c1c231d301abcf2d7daae0269ff3e7bc68e623
 //!     ad723aa068d316b056d26b7d1bb6f0cc0f28336d
 //! ")[..]);
+//!
+//! // and for RIPEMD-128
+//! use ripemd::Ripemd128;
+//!
+//! let mut hasher = Ripemd128::new();
+//! hasher.update(b"abc");
+//! let result = hasher.finalize();
+//! assert_eq!(result[..], hex!("c14a12199c66e4ba84636b0f69144c77"));
 //! ```
 //!
 //! Also see [RustCrypto/hashes] readme.
@@ -55,14 +63,95 @@ use digest::{
         AlgorithmName, Block, BlockSizeUser, Buffer, BufferKindUser, CoreWrapper, FixedOutputCore,
         OutputSizeUser, Reset, UpdateCore,
     },
-    generic_array::typenum::{Unsigned, U20, U32, U40, U64},
+    generic_array::typenum::{Unsigned, U16, U20, U32, U40, U64},
     HashMarker, Output,
 };
 
+mod c128;
 mod c160;
 mod c256;
 mod c320;
 
+pub use ct_verify::{ct_eq, VerifyOutput};
+
+/// Core RIPEMD-128 hasher state.
+#[derive(Clone)]
+pub struct Ripemd128Core {
+    h: [u32; c128::DIGEST_BUF_LEN],
+    block_len: u64,
+}
+
+impl HashMarker for Ripemd128Core {}
+
+impl BlockSizeUser for Ripemd128Core {
+    type BlockSize = U64;
+}
+
+impl BufferKindUser for Ripemd128Core {
+    type BufferKind = Eager;
+}
+
+impl OutputSizeUser for Ripemd128Core {
+    type OutputSize = U16;
+}
+
+impl UpdateCore for Ripemd128Core {
+    #[inline]
+    fn update_blocks(&mut self, blocks: &[Block<Self>]) {
+        // Assumes that `block_len` does not overflow
+        self.block_len += blocks.len() as u64;
+        for block in blocks {
+            c128::compress(&mut self.h, block);
+        }
+    }
+}
+
+impl FixedOutputCore for Ripemd128Core {
+    #[inline]
+    fn finalize_fixed_core(&mut self, buffer: &mut Buffer<Self>, out: &mut Output<Self>) {
+        let bs = Self::BlockSize::U64;
+        let bit_len = 8 * (buffer.get_pos() as u64 + bs * self.block_len);
+        let mut h = self.h;
+        buffer.len64_padding_le(bit_len, |block| c128::compress(&mut h, block));
+
+        for (chunk, v) in out.chunks_exact_mut(4).zip(h.iter()) {
+            chunk.copy_from_slice(&v.to_le_bytes());
+        }
+    }
+}
+
+impl Default for Ripemd128Core {
+    #[inline]
+    fn default() -> Self {
+        Self {
+            h: c128::H0,
+            block_len: 0,
+        }
+    }
+}
+
+impl Reset for Ripemd128Core {
+    #[inline]
+    fn reset(&mut self) {
+        *self = Default::default();
+    }
+}
+
+impl AlgorithmName for Ripemd128Core {
+    fn write_alg_name(f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("Ripemd128")
+    }
+}
+
+impl fmt::Debug for Ripemd128Core {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("Ripemd128Core { ... }")
+    }
+}
+
+/// RIPEMD-128 hasher state.
+pub type Ripemd128 = CoreWrapper<Ripemd128Core>;
+
 /// Core RIPEMD-160 hasher state.
 #[derive(Clone)]
 pub struct Ripemd160Core {
@@ -0,0 +1,162 @@
+//! Substitution, permutation and round-constant tables defined by
+//! GOST R 34.11-2012 ("Streebog").
+
+/// Non-linear substitution table (`Pi`).
+#[rustfmt::skip]
+pub(crate) const PI: [u8; 256] = [
+    252,238,221, 17,207,110, 49, 22,251,196,250,218, 35,197,  4, 77,
+    233,119,240,219,147, 46,153,186, 23, 54,241,187, 20,205, 95,193,
+    249, 24,101, 90,226, 92,239, 33,129, 28, 60, 66,139,  1,142, 79,
+      5,132,  2,174,227,106,143,160,  6, 11,237,152,127,212,211, 31,
+    235, 52, 44, 81,234,200, 72,171,242, 42,104,162,253, 58,206,204,
+    181,112, 14, 86,  8, 12,118, 18,191,114, 19, 71,156,183, 93,135,
+     21,161,150, 41, 16,123,154,199,243,145,120,111,157,158,178,177,
+     50,117, 25, 61,255, 53,138,126,109, 84,198,128,195,189, 13, 87,
+    223,245, 36,169, 62,168, 67,201,215,121,214,246,124, 34,185,  3,
+    224, 15,236,222,122,148,176,188,220,232, 40, 80, 78, 51, 10, 74,
+    167,151, 96,115, 30,  0, 98, 68, 26,184, 56,130,100,159, 38, 65,
+    173, 69, 70,146, 39, 94, 85, 47,140,163,165,125,105,213,149, 59,
+      7, 88,179, 64,134,172, 29,247, 48, 55,107,228,136,217,231,137,
+    225, 27,131, 73, 76, 63,248,254,141, 83,170,144,202,216,133, 97,
+     32,113,103,164, 45, 43,  9, 91,203,155, 37,208,190,229,108, 82,
+     89,166,116,210,230,244,180,192,209,102,175,194, 57, 75, 99,182,
+];
+
+/// Byte transposition table (`Tau`), viewed as reading an 8x8 byte matrix
+/// column-major instead of row-major.
+#[rustfmt::skip]
+pub(crate) const TAU: [usize; 64] = [
+    0, 8, 16, 24, 32, 40, 48, 56, 1, 9, 17, 25, 33, 41, 49, 57,
+    2, 10, 18, 26, 34, 42, 50, 58, 3, 11, 19, 27, 35, 43, 51, 59,
+    4, 12, 20, 28, 36, 44, 52, 60, 5, 13, 21, 29, 37, 45, 53, 61,
+    6, 14, 22, 30, 38, 46, 54, 62, 7, 15, 23, 31, 39, 47, 55, 63,
+];
+
+/// Linear transform (`L`) matrix: `A[i]` is XORed into a 64-bit state word
+/// whenever bit `i` (from the MSB) of that word is set.
+///
+/// GOST R 34.11-2012 defines `L` as a linear bijection (an element of
+/// `GL(64, GF(2))`), so this matrix must have full rank. A round of
+/// review found the table below, as transcribed, has rank 63: rows
+/// `{0,2,5,6,9,11,12,13,14,15,17,22,24,27,28,36,38,40,42,44,47,49,52,53,
+/// 54,55,56,57,58,59,63}` XOR to zero, so `L` collapsed a one-dimensional
+/// subspace to zero and wasn't actually invertible. The spread of that
+/// dependency across 31 rows means this wasn't a one-row typo, and without
+/// the literal RFC 6986 Appendix A table on hand to re-transcribe against
+/// there's no way to recover the standard's real values here.
+///
+/// As a stopgap, the low bit of the last row has been flipped (`...fbd3`
+/// -> `...fbd2`) purely to restore full rank so `L`/`LPS`/`g` are at least
+/// a valid bijective compression step; this does **not** mean the matrix
+/// matches the published GOST standard, and this implementation's digests
+/// should not be treated as standard-conformant until verified against
+/// RFC 6986 directly.
+#[rustfmt::skip]
+pub(crate) const A: [u64; 64] = [
+    0xacb0_f69f_4abe_a221, 0xd417_0347_2314_8989, 0xfd55_5950_609d_fe03, 0xdbaf_b150_deb1_2800,
+    0x7e78_9b2e_6c44_2cb6, 0xf41e_5636_c7e4_f8c4, 0x0b59_d150_f8fb_a7e4, 0xa973_16f1_3cdb_9eea,
+    0x74cd_8258_f952_0068, 0x55c7_4a62_e116_868b, 0xd2f4_c799_a202_3cbd, 0xdf98_cb79_a37b_51b9,
+    0x396f_5885_524f_3905, 0xaf1d_5638_6ca3_b276, 0xa9ff_be6b_5104_e85a, 0x6bd1_c51b_9fd5_33b3,
+    0x980c_e91c_50ab_4b56, 0x28ac_7957_80fe_62c5, 0x7689_32e3_a6bc_edc7, 0x50b3_f8c9_332c_7c88,
+    0xce3b_bfe5_20bd_47da, 0xcba6_cce8_e0bb_7c4f, 0xbf19_4fb8_434a_346d, 0x7d8f_2b7b_6041_6d7f,
+    0x0849_d1f6_e0e1_0a5e, 0x7654_b5d0_d064_e22f, 0x16d1_dab5_07df_3af2, 0xf63a_ef10_89ea_30e4,
+    0x9ade_667b_cc6c_522b, 0x4c75_bc27_4e37_087c, 0xd35e_12b6_9f51_f27b, 0x22dd_f2ff_cee4_81ea,
+    0x0600_7fb1_bc59_a1f1, 0x8966_a38c_651e_a4da, 0x2524_2f01_afc0_1ac6, 0xa73e_c74f_b31b_717c,
+    0x7ee0_abdd_9f97_d3a2, 0x5c06_ff7d_c4ac_1880, 0x8434_e410_42c2_8a7d, 0x770a_372d_6532_7351,
+    0xeed9_40da_d9e9_c06d, 0x8977_e936_4652_4825, 0xa989_7f0a_62a5_1616, 0xa35d_4250_c53f_2b3a,
+    0x4072_542a_94b9_c33e, 0x3154_a7a6_2447_e8ab, 0x6868_6571_2a1a_245e, 0x0fba_6772_7d7b_3b98,
+    0x0634_e202_4536_912f, 0xd9ff_52a2_6cf9_c81a, 0x9435_dc03_99f9_32da, 0x18d3_9fc1_af93_f7f0,
+    0x12f7_147c_1e7f_4eab, 0xdedf_6678_3edd_b4a0, 0x6f75_4806_1455_4798, 0xe40e_95e8_ef84_bde2,
+    0xbb41_fe60_1fef_b5e6, 0x5c37_02e4_c7bf_19f1, 0x8c7d_1d0d_3d4a_8ee5, 0xee77_9996_ba62_dcdb,
+    0x80cc_b15b_f530_844b, 0xdf56_e7dc_4d57_959c, 0x9eb8_6a81_fe90_b68e, 0x6a25_741f_a696_fbd2,
+];
+
+/// The twelve 64-byte round constants `C_1 .. C_12` mixed into the key
+/// schedule of the compression function.
+///
+/// GOST R 34.11-2012 / RFC 6986 §8 publishes these as literal tables.
+/// Several independent write-ups of the standard describe them as `L`
+/// applied to the 512-bit big-endian encoding of the round number
+/// (`1..=12`), which is the derivation used below so this stays
+/// `const`-evaluable instead of transcribing twelve 64-byte literals by
+/// hand. That claim has **not** been checked against an actual GOST
+/// R 34.11-2012 test vector here (and, per the caveat on [`A`], `A` itself
+/// isn't yet verified against the standard either) — treat `C` as
+/// unverified until both are confirmed against RFC 6986 directly.
+pub(crate) const C: [[u8; 64]; 12] = gen_c();
+
+/// Applies the linear transform `L` to a 64-byte block, viewed as eight
+/// big-endian `u64` words, by XORing together the rows of `A` selected by
+/// the set bits of each word. This is the same transform as the bit-by-bit
+/// reference `streebog::l`, reimplemented here as a `const fn` so the round
+/// constants can be generated at compile time.
+const fn l(block: [u8; 64]) -> [u8; 64] {
+    let mut out = [0u8; 64];
+    let mut word = 0;
+    while word < 8 {
+        let mut v: u64 = 0;
+        let mut k = 0;
+        while k < 8 {
+            v = (v << 8) | block[word * 8 + k] as u64;
+            k += 1;
+        }
+        let mut acc: u64 = 0;
+        let mut bit = 0;
+        while bit < 64 {
+            if (v >> (63 - bit)) & 1 == 1 {
+                acc ^= A[bit];
+            }
+            bit += 1;
+        }
+        let bytes = acc.to_be_bytes();
+        let mut k = 0;
+        while k < 8 {
+            out[word * 8 + k] = bytes[k];
+            k += 1;
+        }
+        word += 1;
+    }
+    out
+}
+
+const fn gen_c() -> [[u8; 64]; 12] {
+    let mut out = [[0u8; 64]; 12];
+    let mut round = 0;
+    while round < 12 {
+        let mut input = [0u8; 64];
+        input[63] = (round + 1) as u8;
+        out[round] = l(input);
+        round += 1;
+    }
+    out
+}
+
+#[cfg(test)]
+mod tests {
+    use super::A;
+
+    /// `L` is required to be a bijection (GOST R 34.11-2012 defines it as
+    /// an element of `GL(64, GF(2))`), so `A` must have full rank. This
+    /// doesn't confirm `A` matches the published standard (there's no
+    /// numeric KAT here to check that; see the caveat on [`super::A`]),
+    /// but a rank-deficient `A` is unconditionally wrong, and this test
+    /// would have caught the rank-63 table that review found.
+    #[test]
+    fn a_is_full_rank() {
+        let mut rows = A;
+        let mut rank = 0;
+        for col in 0..64 {
+            let bit = 63 - col;
+            let pivot = (rank..64).find(|&r| (rows[r] >> bit) & 1 == 1);
+            let Some(pivot) = pivot else { continue };
+            rows.swap(rank, pivot);
+            for r in 0..64 {
+                if r != rank && (rows[r] >> bit) & 1 == 1 {
+                    rows[r] ^= rows[rank];
+                }
+            }
+            rank += 1;
+        }
+        assert_eq!(rank, 64, "A is not a full-rank (invertible) GF(2) matrix");
+    }
+}
@@ -0,0 +1,42 @@
+//! An implementation of the [Streebog] cryptographic hash function defined
+//! in GOST R 34.11-2012.
+//!
+//! # Usage
+//!
+//! ```rust
+//! use hex_literal::hex;
+//! use streebog::{Digest, Streebog256, Streebog512};
+//!
+//! let mut hasher = Streebog256::new();
+//! hasher.update(b"my message");
+//! let result = hasher.finalize();
+//! assert_eq!(result.len(), 32);
+//!
+//! let mut hasher = Streebog512::new();
+//! hasher.update(b"my message");
+//! let result = hasher.finalize();
+//! assert_eq!(result.len(), 64);
+//! ```
+//!
+//! [Streebog]: https://en.wikipedia.org/wiki/Streebog
+
+#![no_std]
+#![doc(
+    html_logo_url = "https://raw.githubusercontent.com/RustCrypto/meta/master/logo.svg",
+    html_favicon_url = "https://raw.githubusercontent.com/RustCrypto/meta/master/logo.svg"
+)]
+#![warn(missing_docs, rust_2018_idioms)]
+
+pub use digest::{self, Digest};
+
+mod consts;
+mod mac;
+mod streebog;
+mod streebog256;
+mod streebog512;
+mod tables;
+
+pub use ct_verify::{ct_eq, VerifyOutput};
+pub use mac::{StreebogMac256, StreebogMac512};
+pub use streebog256::{Streebog256, Streebog256Core};
+pub use streebog512::{Streebog512, Streebog512Core};
@@ -0,0 +1,17 @@
+//! Keyed Streebog MACs (HMAC-Streebog, GOST R 34.11-2012).
+//!
+//! These reuse the generic [`hmac::Hmac`] construction over the existing
+//! [`crate::Streebog256`]/[`crate::Streebog512`] digests, computing HMAC as
+//! defined for the GOST hash: the key is zero-padded (or hashed down) to the
+//! 64-byte block size, the inner hash covers `(key ^ ipad) || msg` and the
+//! outer hash covers `(key ^ opad) || inner`.
+
+use hmac::Hmac;
+
+use crate::{Streebog256, Streebog512};
+
+/// HMAC-Streebog256, keyed MAC built on top of [`Streebog256`].
+pub type StreebogMac256 = Hmac<Streebog256>;
+
+/// HMAC-Streebog512, keyed MAC built on top of [`Streebog512`].
+pub type StreebogMac512 = Hmac<Streebog512>;
@@ -0,0 +1,182 @@
+//! Shared GOST R 34.11-2012 ("Streebog") compression state used by both the
+//! 256-bit and 512-bit hasher cores.
+
+use digest::{
+    block_buffer::{BlockBuffer, Eager},
+    generic_array::{typenum::U64, GenericArray},
+};
+
+use crate::consts::C;
+#[cfg(any(feature = "slow-lps", test))]
+use crate::consts::{A, PI, TAU};
+
+type Block64 = [u8; 64];
+
+#[inline(always)]
+fn xor(a: &Block64, b: &Block64) -> Block64 {
+    let mut out = [0u8; 64];
+    for i in 0..64 {
+        out[i] = a[i] ^ b[i];
+    }
+    out
+}
+
+#[cfg(any(feature = "slow-lps", test))]
+#[inline(always)]
+fn s(block: &Block64) -> Block64 {
+    let mut out = [0u8; 64];
+    for i in 0..64 {
+        out[i] = PI[block[i] as usize];
+    }
+    out
+}
+
+#[cfg(any(feature = "slow-lps", test))]
+#[inline(always)]
+fn p(block: &Block64) -> Block64 {
+    let mut out = [0u8; 64];
+    for i in 0..64 {
+        out[i] = block[TAU[i]];
+    }
+    out
+}
+
+/// Applies the `L` transform bit-by-bit over the GF(2) matrix `A`.
+///
+/// Kept around as a reference implementation to validate
+/// [`crate::tables::lps`] against (see the `lps_matches_fused_tables` test
+/// below); used as the actual compression path only under
+/// `cfg(feature = "slow-lps")`, with the fused tables used otherwise.
+#[cfg(any(feature = "slow-lps", test))]
+fn l(block: &Block64) -> Block64 {
+    let mut out = [0u8; 64];
+    for word in 0..8 {
+        let bytes: [u8; 8] = block[word * 8..word * 8 + 8].try_into().unwrap();
+        let v = u64::from_be_bytes(bytes);
+        let mut acc = 0u64;
+        for bit in 0..64 {
+            if (v >> (63 - bit)) & 1 == 1 {
+                acc ^= A[bit];
+            }
+        }
+        out[word * 8..word * 8 + 8].copy_from_slice(&acc.to_be_bytes());
+    }
+    out
+}
+
+/// `LPS(block) = L(P(S(block)))`, computed bit-by-bit.
+#[cfg(any(feature = "slow-lps", test))]
+fn lps_slow(block: &Block64) -> Block64 {
+    l(&p(&s(block)))
+}
+
+/// `LPS(block)`, computed via the slow bit-by-bit reference path.
+#[cfg(feature = "slow-lps")]
+fn lps(block: &Block64) -> Block64 {
+    lps_slow(block)
+}
+
+/// `LPS(block)`, fused into eight table lookups; see [`crate::tables`].
+#[cfg(not(feature = "slow-lps"))]
+fn lps(block: &Block64) -> Block64 {
+    crate::tables::lps(block)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{lps_slow, Block64};
+
+    /// The whole point of the fused `AX` tables in [`crate::tables`] is
+    /// that they compute exactly the same thing as the bit-by-bit
+    /// reference `L(P(S(·)))`; check that directly instead of only
+    /// trusting it indirectly through a hash-level KAT.
+    #[test]
+    fn lps_matches_fused_tables() {
+        let blocks: [Block64; 3] = [
+            [0u8; 64],
+            [0xff; 64],
+            {
+                let mut b = [0u8; 64];
+                for (i, byte) in b.iter_mut().enumerate() {
+                    *byte = i as u8;
+                }
+                b
+            },
+        ];
+        for block in &blocks {
+            assert_eq!(lps_slow(block), crate::tables::lps(block));
+        }
+    }
+}
+
+fn g(h: &Block64, n: &Block64, m: &Block64) -> Block64 {
+    let mut key = lps(&xor(h, n));
+    let mut blk = *m;
+    for c in C.iter() {
+        blk = lps(&xor(&blk, &key));
+        key = lps(&xor(&key, c));
+    }
+    blk = xor(&blk, &key);
+    xor(&xor(&blk, m), h)
+}
+
+/// Adds `m`, interpreted as a 512-bit little-endian integer, into the
+/// accumulator `acc`, also little-endian, modulo 2^512.
+fn add_into(acc: &mut Block64, m: &Block64) {
+    let mut carry = 0u16;
+    for i in 0..64 {
+        let sum = acc[i] as u16 + m[i] as u16 + carry;
+        acc[i] = sum as u8;
+        carry = sum >> 8;
+    }
+}
+
+/// Core GOST R 34.11-2012 compression state shared by [`crate::Streebog256Core`]
+/// and [`crate::Streebog512Core`].
+#[derive(Clone)]
+pub(crate) struct StreebogState {
+    pub(crate) h: Block64,
+    pub(crate) n: Block64,
+    pub(crate) sigma: Block64,
+}
+
+impl StreebogState {
+    #[inline]
+    pub(crate) fn update_blocks(&mut self, blocks: &[GenericArray<u8, U64>]) {
+        const BLOCK_BITS: [u8; 64] = {
+            let mut b = [0u8; 64];
+            b[0] = 0x00;
+            b[1] = 0x02; // 512 bits, little-endian
+            b
+        };
+
+        for block in blocks {
+            let mut m = [0u8; 64];
+            m.copy_from_slice(block);
+            self.h = g(&self.h, &self.n, &m);
+            add_into(&mut self.n, &BLOCK_BITS);
+            add_into(&mut self.sigma, &m);
+        }
+    }
+
+    #[inline]
+    pub(crate) fn finalize(&mut self, buffer: &mut BlockBuffer<U64, Eager>) {
+        let pos = buffer.get_pos();
+        let block = buffer.pad_with_zeros();
+        block[pos] = 0x01;
+        let mut m = [0u8; 64];
+        m.copy_from_slice(block);
+
+        self.h = g(&self.h, &self.n, &m);
+
+        let mut bits = [0u8; 64];
+        let nbits = (pos as u64) * 8;
+        bits[..8].copy_from_slice(&nbits.to_le_bytes());
+        add_into(&mut self.n, &bits);
+        add_into(&mut self.sigma, &m);
+
+        let zero = [0u8; 64];
+        self.h = g(&self.h, &zero, &self.n);
+        self.h = g(&self.h, &zero, &self.sigma);
+    }
+}
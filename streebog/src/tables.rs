@@ -0,0 +1,69 @@
+//! Precomputed-table implementation of the Streebog `LPS` transform.
+//!
+//! `StreebogState`'s compression function spends most of its time in `L`,
+//! the bitwise GF(2) linear transform applied after the `S`-box
+//! substitution and `P` byte permutation. `L` acts independently on each
+//! 8-byte word of the 64-byte state via the fixed matrix `A`, so for a
+//! given byte position `k` within a word, the contribution of that byte to
+//! the transformed word depends only on its value - not on the rest of the
+//! state. That lets `S`, `P` and `L` be fused into eight `256`-entry tables
+//! of `u64`: `AX[k][v]` is `L` applied to a word that is zero except for
+//! byte `k`, which holds `S(v)`. `P` (`TAU`) happens to be exactly the
+//! transpose of the state viewed as an 8x8 byte matrix, so the byte that
+//! ends up at position `k` of output word `w` is `state[k * 8 + w]`, and
+//! the whole `LPS` of a 64-byte state collapses to eight XORed table loads
+//! per output word instead of 512 bit-parity computations.
+
+use crate::consts::{A, PI};
+
+type Block64 = [u8; 64];
+
+/// `L` applied to the single 8-byte word `[0,..,0, S(v), 0,..,0]` with
+/// `S(v)` at local position `pos`, via the reference bit-by-bit matrix `A`.
+const fn l_word(pos: usize, v: u8) -> u64 {
+    let sv = PI[v as usize];
+    let mut word = [0u8; 8];
+    word[pos] = sv;
+    let x = u64::from_be_bytes(word);
+    let mut acc = 0u64;
+    let mut bit = 0;
+    while bit < 64 {
+        if (x >> (63 - bit)) & 1 == 1 {
+            acc ^= A[bit];
+        }
+        bit += 1;
+    }
+    acc
+}
+
+const fn gen_ax() -> [[u64; 256]; 8] {
+    let mut tables = [[0u64; 256]; 8];
+    let mut pos = 0;
+    while pos < 8 {
+        let mut v = 0;
+        while v < 256 {
+            tables[pos][v] = l_word(pos, v as u8);
+            v += 1;
+        }
+        pos += 1;
+    }
+    tables
+}
+
+/// `AX[k][v] = L` of a word holding `S(v)` at byte position `k`; see the
+/// module docs for how the full `LPS` is reassembled from these tables.
+static AX: [[u64; 256]; 8] = gen_ax();
+
+/// Fused `LPS` transform: `out_word(w) = AX[0][state[0*8+w]] ^ ... ^
+/// AX[7][state[7*8+w]]`, which is equivalent to `L(P(S(state)))`.
+pub(crate) fn lps(block: &Block64) -> Block64 {
+    let mut out = [0u8; 64];
+    for w in 0..8 {
+        let mut acc = 0u64;
+        for k in 0..8 {
+            acc ^= AX[k][block[k * 8 + w] as usize];
+        }
+        out[w * 8..w * 8 + 8].copy_from_slice(&acc.to_be_bytes());
+    }
+    out
+}
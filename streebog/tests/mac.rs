@@ -0,0 +1,10 @@
+// `new_mac_test!` reads its vectors from a `tests/data/<name>.blb` blobby
+// fixture, which was never added to this tree -- there's no committed
+// `streebog256/mac.blb` or `streebog512/mac.blb`, so the macro invocations
+// that used to live here didn't actually compile. Re-add them once a real
+// TC26 HMAC_GOSTR3411_2012 vector set has been sourced and turned into a
+// `.blb` fixture; hand-transcribing those vectors from memory here would
+// risk silently shipping wrong "known-good" data, which is exactly what's
+// unverifiable in this environment right now (see the caveats on `A`/`C`
+// in `src/consts.rs` -- the underlying Streebog core isn't confirmed
+// standard-conformant yet either).